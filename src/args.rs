@@ -1,6 +1,9 @@
 use anstyle::{AnsiColor, Color, Style};
 use clap::{builder::Styles, Parser};
 
+use crate::segment::SegmentSpec;
+use crate::timecode::Timecode;
+
 /// Generate a video clip from the command-line in a configurable way.
 ///
 /// https://github.com/siph/clipwhisper
@@ -13,21 +16,77 @@ pub struct Args {
     #[arg(short, long)]
     pub input: String,
 
-    /// The path for the final video output. THIS WILL OVERWRITE EXISTING FILES!
+    /// The path for the final video output. THIS WILL OVERWRITE EXISTING FILES! When
+    /// `--auto-scenes` is set, this is instead treated as an output-name template supporting
+    /// `{index}`/`{start}` interpolation, e.g. `scene-{index}.mp4`.
     #[arg(short, long)]
     pub output: String,
 
-    /// The length in seconds of the final desired clip. A duration that exceeds the remaining
-    /// video runtime will be bound within the available duration, resulting in a clip that is
-    /// shorter than the provided duration.
-    #[arg(short, long, default_value_t = 10)]
-    pub duration: u32,
+    /// The length of the final desired clip. Accepts plain seconds (`12.5`) or a timecode
+    /// (`HH:MM:SS.mmm`, e.g. `1:23:45.500`). A duration that exceeds the remaining video runtime
+    /// will be bound within the available duration, resulting in a clip that is shorter than the
+    /// provided duration.
+    #[arg(short, long, default_value_t = Timecode::from_seconds(10.0))]
+    pub duration: Timecode,
+
+    /// Denote where the clip should begin. Accepts plain seconds (`12.5`) or a timecode
+    /// (`HH:MM:SS.mmm`, e.g. `0:05,250`). An offset that surpasses the length of the input video
+    /// will be bound to the available duration and result in an empty or very short clip.
+    #[arg(short = 's', long, default_value_t = Timecode::from_seconds(0.0))]
+    pub offset: Timecode,
+
+    /// Stream-copy the clip instead of re-encoding it. This seeks the input directly to `offset`
+    /// (`-ss` before `-i`) and copies the codec, which is much faster but snaps `start` to the
+    /// nearest preceding keyframe, so the clip may begin slightly earlier than requested.
+    #[arg(long, default_value_t = false)]
+    pub copy: bool,
+
+    /// Extract multiple segments and concatenate them into a single output. Repeatable; each
+    /// value is a `start-end` timecode range, e.g. `--segment 0:10-0:25 --segment 1:00-1:15`.
+    /// When given, `--offset`/`--duration` are ignored in favor of the segment list.
+    #[arg(long = "segment")]
+    pub segments: Vec<SegmentSpec>,
+
+    /// Automatically split the input into clips at detected scene changes, instead of requiring
+    /// explicit `--offset`/`--duration`. One output is rendered per scene; see `--output` for the
+    /// naming template. Overrides `--segment`: each scene is rendered from its own detected
+    /// range rather than the segment list.
+    #[arg(long)]
+    pub auto_scenes: bool,
+
+    /// Minimum `scene` score change (0.0-1.0) to be considered a cut point when `--auto-scenes`
+    /// is set.
+    #[arg(long, default_value_t = 0.3)]
+    pub scene_threshold: f32,
+
+    /// Minimum scene length, in seconds, to keep when `--auto-scenes` is set. Shorter scenes are
+    /// dropped to avoid noise from spurious cuts.
+    #[arg(long, default_value_t = 1.0)]
+    pub scene_min_length: f32,
+
+    /// Relocate the `moov` atom before `mdat` (`-movflags +faststart`) so the clip can start
+    /// playing before it's fully downloaded. Only applied when `--output` is an MP4/MOV family
+    /// container.
+    #[arg(long, default_value_t = false)]
+    pub faststart: bool,
+
+    /// Emit fragmented MP4/CMAF output (`-movflags +frag_keyframe+empty_moov+default_base_moof`)
+    /// suitable for HLS/DASH. Only applied when `--output` is an MP4/MOV family container.
+    #[arg(long, default_value_t = false)]
+    pub fragmented: bool,
+
+    /// Ease into the clip instead of hard-cutting, over the given duration. Accepts plain seconds
+    /// (`12.5`) or a timecode (`HH:MM:SS.mmm`). Clamped to the length of the clip. Only applies
+    /// in the default precise mode; ignored (with a warning) alongside `--copy`/`--segment`.
+    #[arg(long)]
+    pub fade_in: Option<Timecode>,
 
-    /// Denote in seconds where the clip should begin. An offset that surpasses the length of the
-    /// input video will be bound to the available duration and result in an empty or very short
-    /// clip.
-    #[arg(short = 's', long, default_value_t = 0)]
-    pub offset: u32,
+    /// Ease out of the clip instead of hard-cutting, over the given duration. Accepts plain
+    /// seconds (`12.5`) or a timecode (`HH:MM:SS.mmm`). Clamped to the length of the clip. Only
+    /// applies in the default precise mode; ignored (with a warning) alongside
+    /// `--copy`/`--segment`.
+    #[arg(long)]
+    pub fade_out: Option<Timecode>,
 }
 
 fn get_styles() -> Styles {
@@ -1,11 +1,29 @@
 use std::collections::HashMap;
 
 use args::Args;
+use timecode::Timecode;
 
 use interpolator::{format, Formattable};
 use log::{debug, warn};
 
 pub mod args;
+pub mod scenes;
+pub mod segment;
+pub mod timecode;
+
+/// Interpolates `{index}`/`{start}` into an output filename template (e.g. `scene-{index}.mp4`
+/// -> `scene-0.mp4`), for naming the per-scene outputs produced by `--auto-scenes`. Uses the same
+/// `interpolator::format` path as `CommandChunk::format_chunk`.
+pub fn format_output_template(template: &str, index: usize, start: f64) -> String {
+    let formats = &[
+        ("index", Formattable::display(&index)),
+        ("start", Formattable::display(&start)),
+    ]
+    .into_iter()
+    .collect::<HashMap<_, _>>();
+
+    format(template, formats).expect("Failed to dynamically format output template")
+}
 
 /// Represents a cli command to extract a clip from a video.
 #[derive(PartialEq, Clone, Debug)]
@@ -22,10 +40,44 @@ pub struct ClipCommand {
     pub output: CommandChunk,
     /// Start and end timestamps
     pub target: TargetTimeStamp,
+    /// When `true`, stream-copy the clip via `CopyInput` instead of running the
+    /// `video_filter`/`audio_filter` pipeline.
+    pub copy: bool,
+    /// Segments to extract and concatenate via `ConcatFilter`. When non-empty this takes
+    /// priority over `target`/`copy`, which describe a single clip.
+    pub segments: Vec<TargetTimeStamp>,
+    /// When `true` and `output` is an MP4/MOV family container, append `-movflags +faststart`
+    /// so the clip can begin playing before it's fully downloaded.
+    pub faststart: bool,
+    /// When `true` and `output` is an MP4/MOV family container, append `-movflags
+    /// +frag_keyframe+empty_moov+default_base_moof` to produce fragmented MP4 for HLS/DASH.
+    pub fragmented: bool,
+    /// Fade-in/fade-out durations, in seconds, injected into `video_filter`/`audio_filter`. Each
+    /// duration is clamped to the clip's length when the filters are formatted.
+    pub fade: Fade,
 }
 
 impl From<Args> for ClipCommand {
     fn from(args: Args) -> Self {
+        let fade = Fade {
+            fade_in: args.fade_in.map(|timecode| timecode.seconds),
+            fade_out: args.fade_out.map(|timecode| timecode.seconds),
+        };
+
+        let mut video_filter_value =
+            "select='between(t,{start},{end})',setpts=N/FRAME_RATE/TB".to_string();
+        let mut audio_filter_value = "aselect='between(t,{start},{end})',asetpts=N/SR/TB".to_string();
+
+        if fade.fade_in.is_some() {
+            video_filter_value.push_str(",fade=t=in:st={start}:d={fade_in_duration}");
+            audio_filter_value.push_str(",afade=t=in:st={start}:d={fade_in_duration}");
+        }
+        if fade.fade_out.is_some() {
+            video_filter_value.push_str(",fade=t=out:st={fade_out_start}:d={fade_out_duration}");
+            audio_filter_value
+                .push_str(",afade=t=out:st={fade_out_start}:d={fade_out_duration}");
+        }
+
         Self {
             executable: "ffmpeg".to_string(),
             input: CommandChunk {
@@ -34,33 +86,79 @@ impl From<Args> for ClipCommand {
             },
             video_filter: CommandChunk {
                 flag: "-vf".to_string(),
-                value: "select='between(t,{start},{end})',setpts=N/FRAME_RATE/TB".to_string(),
+                value: video_filter_value,
             },
             audio_filter: CommandChunk {
                 flag: "-af".to_string(),
-                value: "aselect='between(t,{start},{end})',asetpts=N/SR/TB".to_string(),
+                value: audio_filter_value,
             },
             output: CommandChunk {
                 flag: "-o".to_string(),
                 value: args.output,
             },
             target: TargetTimeStamp::new(args.offset, args.duration),
+            copy: args.copy,
+            segments: args
+                .segments
+                .iter()
+                .map(|segment| TargetTimeStamp {
+                    start: segment.start.seconds,
+                    end: segment.end.seconds,
+                })
+                .collect(),
+            faststart: args.faststart,
+            fragmented: args.fragmented,
+            fade,
         }
     }
 }
 
 impl ClipCommand {
     /// Format and display the arguments as a list of strings.
+    ///
+    /// When `segments` is non-empty this renders a `ConcatFilter`, which joins every segment
+    /// into a single output via `filter_complex`. Otherwise, in the default precise mode this
+    /// renders the `-i`/`-vf`/`-af` filter pipeline, which re-encodes the clip to cut on an exact
+    /// frame. In `copy` mode it instead renders a `CopyInput`, which seeks before `-i` and
+    /// stream-copies the codec: much faster, but `start` snaps to the nearest preceding keyframe
+    /// rather than the exact requested time. `fade` is only honored in the default precise mode;
+    /// a `warn!` is logged if it's set alongside `copy`/`segments`, where it has no effect.
     pub fn render_arguments(&self) -> Vec<String> {
         debug!("Rendering arguments for: {:#?}", self);
-        let mut arguments: Vec<String> = vec![
-            self.input.clone(),
-            self.video_filter.format_chunk(&self.target),
-            self.audio_filter.format_chunk(&self.target),
-        ]
-        .into_iter()
-        .flat_map(|it| vec![it.flag, it.value])
-        .collect();
+
+        if (self.copy || !self.segments.is_empty())
+            && (self.fade.fade_in.is_some() || self.fade.fade_out.is_some())
+        {
+            warn!(
+                "--fade-in/--fade-out only apply in the default precise mode; ignoring them for \
+                 this {} invocation.",
+                if !self.segments.is_empty() {
+                    "--segment"
+                } else {
+                    "--copy"
+                }
+            );
+        }
+
+        let mut arguments: Vec<String> = match (&self.segments[..], self.copy) {
+            ([], true) => CopyInput::new(self.input.value.clone(), self.target).render_arguments(),
+            ([], false) => vec![
+                self.input.clone(),
+                self.video_filter.format_chunk(&self.target, &self.fade),
+                self.audio_filter.format_chunk(&self.target, &self.fade),
+            ]
+            .into_iter()
+            .flat_map(|it| vec![it.flag, it.value])
+            .collect(),
+            (segments, _) => ConcatFilter::new(self.input.value.clone(), segments.to_vec())
+                .render_arguments(),
+        };
+
+        arguments.extend(
+            self.movflags_chunks()
+                .into_iter()
+                .flat_map(|it| vec![it.flag, it.value]),
+        );
 
         // This adds a flag to overwrite any file with the same path as `output`.
         arguments.push("-y".to_string());
@@ -69,29 +167,160 @@ impl ClipCommand {
         arguments.push(self.output.value.clone());
         arguments
     }
+
+    /// Builds the `-movflags` chunks requested by `faststart`/`fragmented`, if any. These are
+    /// only emitted when `output` is an MP4/MOV family container, since other containers don't
+    /// support `movflags`.
+    fn movflags_chunks(&self) -> Vec<CommandChunk> {
+        if !self.output_is_mp4_family() {
+            return Vec::new();
+        }
+
+        let mut chunks = Vec::new();
+        if self.faststart {
+            chunks.push(CommandChunk {
+                flag: "-movflags".to_string(),
+                value: "+faststart".to_string(),
+            });
+        }
+        if self.fragmented {
+            chunks.push(CommandChunk {
+                flag: "-movflags".to_string(),
+                value: "+frag_keyframe+empty_moov+default_base_moof".to_string(),
+            });
+        }
+        chunks
+    }
+
+    /// Whether `output` has an MP4/MOV family extension (`mp4`, `mov`, `m4v`).
+    fn output_is_mp4_family(&self) -> bool {
+        let extension = std::path::Path::new(&self.output.value)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+        matches!(extension.as_deref(), Some("mp4") | Some("mov") | Some("m4v"))
+    }
+}
+
+/// Builds the argument sequence for stream-copy mode, where `-ss` is given before `-i` so
+/// `ffmpeg` seeks the input directly instead of decoding up to `start`. This is much faster, but
+/// `ffmpeg` can only cut on a keyframe boundary this way, so `start` snaps to the nearest
+/// preceding keyframe rather than landing exactly on the requested time.
+#[derive(PartialEq, Clone, Debug)]
+pub struct CopyInput {
+    /// Input file, seeked to `start` before being opened.
+    pub input: String,
+    /// Seek position, in seconds, applied before `-i`.
+    pub start: f64,
+    /// End position, in seconds. The clip length `end - start` is passed to `-t`, since an output
+    /// `-to` after an input `-ss` is measured from the seek point rather than the input's start.
+    pub end: f64,
+    /// Codec to pass to `-c`. Always `"copy"` for stream-copy mode.
+    pub codec: String,
+}
+
+impl CopyInput {
+    /// Builds a `CopyInput` that seeks to `target.start` and copies through to `target.end`.
+    pub fn new(input: String, target: TargetTimeStamp) -> Self {
+        Self {
+            input,
+            start: target.start,
+            end: target.end,
+            codec: "copy".to_string(),
+        }
+    }
+
+    /// Format and display the arguments as a list of strings.
+    pub fn render_arguments(&self) -> Vec<String> {
+        vec![
+            "-ss".to_string(),
+            self.start.to_string(),
+            "-i".to_string(),
+            self.input.clone(),
+            "-t".to_string(),
+            (self.end - self.start).to_string(),
+            "-c".to_string(),
+            self.codec.clone(),
+        ]
+    }
+}
+
+/// Builds the `-filter_complex` argument that extracts and concatenates multiple segments from
+/// a single input into one output, via ffmpeg's `concat` filter.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ConcatFilter {
+    /// Input file that every segment is trimmed from.
+    pub input: String,
+    /// Segments to extract, in the order they should be concatenated.
+    pub segments: Vec<TargetTimeStamp>,
+}
+
+impl ConcatFilter {
+    /// Builds a `ConcatFilter` over `segments`, extracted from `input`.
+    pub fn new(input: String, segments: Vec<TargetTimeStamp>) -> Self {
+        Self { input, segments }
+    }
+
+    /// Renders the `trim`/`atrim` + `setpts`/`asetpts` chain for each segment, followed by a
+    /// `concat` filter joining them, and returns the full `-i`/`-filter_complex`/`-map` argument
+    /// list.
+    pub fn render_arguments(&self) -> Vec<String> {
+        let mut filter = String::new();
+        let mut labels = String::new();
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            filter.push_str(&format!(
+                "[0:v]trim=start={}:end={},setpts=PTS-STARTPTS[v{index}];",
+                segment.start, segment.end
+            ));
+            filter.push_str(&format!(
+                "[0:a]atrim=start={}:end={},asetpts=PTS-STARTPTS[a{index}];",
+                segment.start, segment.end
+            ));
+            // ffmpeg's `concat` filter groups inputs per segment (`[v0][a0][v1][a1]...`), not per
+            // stream type, so video/audio pads for the same segment must stay adjacent.
+            labels.push_str(&format!("[v{index}][a{index}]"));
+        }
+
+        filter.push_str(&format!(
+            "{labels}concat=n={}:v=1:a=1[outv][outa]",
+            self.segments.len()
+        ));
+
+        vec![
+            "-i".to_string(),
+            self.input.clone(),
+            "-filter_complex".to_string(),
+            filter,
+            "-map".to_string(),
+            "[outv]".to_string(),
+            "-map".to_string(),
+            "[outa]".to_string(),
+        ]
+    }
 }
 
 /// Specifies where the clip exists within the video.
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub struct TargetTimeStamp {
-    /// Start time in seconds
-    pub start: u32,
-    /// End time in seconds
-    pub end: u32,
+    /// Start time in seconds, with sub-second precision preserved.
+    pub start: f64,
+    /// End time in seconds, with sub-second precision preserved.
+    pub end: f64,
 }
 
 impl TargetTimeStamp {
     /// Overflow safe `TargetTimeStamp` builder.
-    pub fn new(offset: u32, duration: u32) -> Self {
-        let start = offset;
-        let end = match offset.overflowing_add(duration) {
-            (_, true) => {
+    pub fn new(offset: Timecode, duration: Timecode) -> Self {
+        let start = offset.seconds;
+        let end = match start + duration.seconds {
+            end if !end.is_finite() => {
                 warn!("Locking end to prevent overflow.");
                 warn!("Start: {:?}", start);
                 warn!("Duration: {:?}", duration);
-                u32::max_value()
+                f64::MAX
             }
-            (end, false) => end,
+            end => end,
         };
         Self { start, end }
     }
@@ -99,9 +328,7 @@ impl TargetTimeStamp {
     /// Bind `start` and `end` values to be valid within the available `max_length`.
     pub fn bind_values(&mut self, max_length: f32) -> Self {
         debug!("Checking if values need binding: {:#?}", &self);
-        // truncate decimals. I think the implication of this is that it will be impossible to get
-        // the last fraction of a second in a clip. But it sure makes the math easier.
-        let video_length = max_length as u32;
+        let video_length = max_length as f64;
 
         // Bind `start` only if it exceeds video length.
         self.start = match video_length {
@@ -138,6 +365,15 @@ impl TargetTimeStamp {
     }
 }
 
+/// Fade-in/fade-out durations, in seconds, to inject into `video_filter`/`audio_filter`.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Fade {
+    /// Duration to fade in from the start of the clip, if any.
+    pub fade_in: Option<f64>,
+    /// Duration to fade out into the end of the clip, if any.
+    pub fade_out: Option<f64>,
+}
+
 /// Represents a key/value command segment.
 #[derive(PartialEq, Clone, Debug)]
 pub struct CommandChunk {
@@ -148,12 +384,23 @@ pub struct CommandChunk {
 }
 
 impl CommandChunk {
-    /// Returns `CommandChunk` with interpolated `start` and `end` for given `TargetTimeStamp`.
-    pub fn format_chunk(&self, target: &TargetTimeStamp) -> Self {
+    /// Returns `CommandChunk` with interpolated `start`/`end` for the given `TargetTimeStamp`,
+    /// plus `fade_in_duration`/`fade_out_start`/`fade_out_duration` for `fade`. Fade durations
+    /// are clamped to the clip's length (`end - start`) so a fade longer than the clip doesn't
+    /// overrun it.
+    pub fn format_chunk(&self, target: &TargetTimeStamp, fade: &Fade) -> Self {
         debug!("Formatting chunk: {:#?}", self);
+        let clip_length = target.end - target.start;
+        let fade_in_duration = fade.fade_in.unwrap_or(0.0).min(clip_length);
+        let fade_out_duration = fade.fade_out.unwrap_or(0.0).min(clip_length);
+        let fade_out_start = target.end - fade_out_duration;
+
         let formats = &[
             ("start", Formattable::display(&target.start)),
             ("end", Formattable::display(&target.end)),
+            ("fade_in_duration", Formattable::display(&fade_in_duration)),
+            ("fade_out_start", Formattable::display(&fade_out_start)),
+            ("fade_out_duration", Formattable::display(&fade_out_duration)),
         ]
         .into_iter()
         .collect::<HashMap<_, _>>();
@@ -172,6 +419,8 @@ pub mod tests {
 
     use quickcheck::Arbitrary;
 
+    use crate::segment::SegmentSpec;
+
     use super::*;
 
     #[quickcheck_macros::quickcheck]
@@ -180,38 +429,59 @@ pub mod tests {
         assert_eq!(result.executable, "ffmpeg".to_string());
         assert_eq!(result.input.value, args.input);
         assert_eq!(result.output.value, args.output);
-        assert_eq!(result.target.start, args.offset);
-        let expected_end = match args.offset.overflowing_add(args.duration) {
-            (end, false) => end,
-            (_, true) => u32::max_value(),
-        };
-        assert_eq!(result.target.end, expected_end);
+        assert_eq!(result.target.start, args.offset.seconds);
+        assert_eq!(result.target.end, args.offset.seconds + args.duration.seconds);
+        assert_eq!(result.copy, args.copy);
+        assert_eq!(result.segments.len(), args.segments.len());
+        for (segment, spec) in result.segments.iter().zip(args.segments.iter()) {
+            assert_eq!(segment.start, spec.start.seconds);
+            assert_eq!(segment.end, spec.end.seconds);
+        }
+        assert_eq!(result.faststart, args.faststart);
+        assert_eq!(result.fragmented, args.fragmented);
+        assert_eq!(
+            result.fade.fade_in,
+            args.fade_in.map(|timecode| timecode.seconds)
+        );
+        assert_eq!(
+            result.fade.fade_out,
+            args.fade_out.map(|timecode| timecode.seconds)
+        );
     }
 
     #[quickcheck_macros::quickcheck]
-    fn test_target_end_is_after_start(offset: u32, duration: u32) {
+    fn test_target_end_is_after_start(offset: Timecode, duration: Timecode) {
         let target = TargetTimeStamp::new(offset, duration);
         assert!(target.start <= target.end);
     }
 
     #[quickcheck_macros::quickcheck]
-    fn test_out_of_range_values_are_bound(offset: u32, duration: u32, video_length: f32) {
+    fn test_out_of_range_values_are_bound(
+        offset: Timecode,
+        duration: Timecode,
+        video_length: f32,
+    ) -> quickcheck::TestResult {
+        // A NaN `video_length` is never a probed duration, and no value compares `<=` to NaN.
+        if video_length.is_nan() {
+            return quickcheck::TestResult::discard();
+        }
         let target = TargetTimeStamp::new(offset, duration).bind_values(video_length);
 
         // If the offset exceeds the `video_length` then it should be bound to the nearest valid
         // value, which would be the last frame of the video represented by `video_length`.
         // Otherwise it should just be offset.
-        if offset > video_length as u32 {
-            assert!(target.start == video_length as u32);
+        if offset.seconds > video_length as f64 {
+            assert!(target.start == video_length as f64);
         } else {
-            assert!(target.start == offset);
-            assert!(target.start <= video_length as u32);
+            assert!(target.start == offset.seconds);
+            assert!(target.start <= video_length as f64);
         }
 
         // `start` doesn't get moved to after `end`.
         assert!(target.start <= target.end);
         // `end` is bound within the `video_length`.
-        assert!(target.end <= video_length as u32);
+        assert!(target.end <= video_length as f64);
+        quickcheck::TestResult::passed()
     }
 
     #[quickcheck_macros::quickcheck]
@@ -220,37 +490,103 @@ pub mod tests {
         let start = target.start;
         let end = target.end;
 
-        let video_expected = format!(
+        let mut video_expected = format!(
             "select='between(t,{},{})',setpts=N/FRAME_RATE/TB",
             start, end
         );
+        let mut audio_expected = format!("aselect='between(t,{},{})',asetpts=N/SR/TB", start, end);
+
+        let clip_length = end - start;
+        if let Some(fade_in) = command.fade.fade_in {
+            let fade_in_duration = fade_in.min(clip_length);
+            video_expected.push_str(&format!(",fade=t=in:st={start}:d={fade_in_duration}"));
+            audio_expected.push_str(&format!(",afade=t=in:st={start}:d={fade_in_duration}"));
+        }
+        if let Some(fade_out) = command.fade.fade_out {
+            let fade_out_duration = fade_out.min(clip_length);
+            let fade_out_start = end - fade_out_duration;
+            video_expected.push_str(&format!(
+                ",fade=t=out:st={fade_out_start}:d={fade_out_duration}"
+            ));
+            audio_expected.push_str(&format!(
+                ",afade=t=out:st={fade_out_start}:d={fade_out_duration}"
+            ));
+        }
+
         assert_eq!(
             video_expected,
-            command.video_filter.format_chunk(&target).value
+            command.video_filter.format_chunk(&target, &command.fade).value
         );
-
-        let audio_expected = format!("aselect='between(t,{},{})',asetpts=N/SR/TB", start, end);
         assert_eq!(
             audio_expected,
-            command.audio_filter.format_chunk(&target).value
+            command.audio_filter.format_chunk(&target, &command.fade).value
         );
     }
 
     #[quickcheck_macros::quickcheck]
     fn test_argument_list_is_rendered(command: ClipCommand) {
-        let expected = vec![
-            command.input.flag.clone(),
-            command.input.value.clone(),
-            command.video_filter.flag.clone(),
-            command.video_filter.format_chunk(&command.target).value,
-            command.audio_filter.flag.clone(),
-            command.audio_filter.format_chunk(&command.target).value,
-            "-y".to_string(),
-            command.output.value.clone(),
-        ];
+        let mut expected = match (&command.segments[..], command.copy) {
+            ([], true) => {
+                CopyInput::new(command.input.value.clone(), command.target).render_arguments()
+            }
+            ([], false) => vec![
+                command.input.flag.clone(),
+                command.input.value.clone(),
+                command.video_filter.flag.clone(),
+                command
+                    .video_filter
+                    .format_chunk(&command.target, &command.fade)
+                    .value,
+                command.audio_filter.flag.clone(),
+                command
+                    .audio_filter
+                    .format_chunk(&command.target, &command.fade)
+                    .value,
+            ],
+            (segments, _) => {
+                ConcatFilter::new(command.input.value.clone(), segments.to_vec()).render_arguments()
+            }
+        };
+        expected.extend(
+            command
+                .movflags_chunks()
+                .into_iter()
+                .flat_map(|it| vec![it.flag, it.value]),
+        );
+        expected.push("-y".to_string());
+        expected.push(command.output.value.clone());
         assert!(expected.eq(&command.render_arguments()));
     }
 
+    #[test]
+    fn test_concat_filter_interleaves_pads_per_segment() {
+        let segments = vec![
+            TargetTimeStamp { start: 10.0, end: 25.0 },
+            TargetTimeStamp { start: 60.0, end: 75.0 },
+        ];
+        let filter = ConcatFilter::new("input.mp4".to_string(), segments).render_arguments();
+
+        let expected_filter_complex = "[0:v]trim=start=10:end=25,setpts=PTS-STARTPTS[v0];\
+             [0:a]atrim=start=10:end=25,asetpts=PTS-STARTPTS[a0];\
+             [0:v]trim=start=60:end=75,setpts=PTS-STARTPTS[v1];\
+             [0:a]atrim=start=60:end=75,asetpts=PTS-STARTPTS[a1];\
+             [v0][a0][v1][a1]concat=n=2:v=1:a=1[outv][outa]";
+
+        assert_eq!(
+            vec![
+                "-i".to_string(),
+                "input.mp4".to_string(),
+                "-filter_complex".to_string(),
+                expected_filter_complex.to_string(),
+                "-map".to_string(),
+                "[outv]".to_string(),
+                "-map".to_string(),
+                "[outa]".to_string(),
+            ],
+            filter
+        );
+    }
+
     impl Arbitrary for ClipCommand {
         fn arbitrary(g: &mut quickcheck::Gen) -> Self {
             Args::arbitrary(g).into()
@@ -261,14 +597,57 @@ pub mod tests {
         fn arbitrary(g: &mut quickcheck::Gen) -> Self {
             let input = PathBuf::arbitrary(g).to_str().unwrap().to_string();
             let output = PathBuf::arbitrary(g).to_str().unwrap().to_string();
-            let offset = u32::arbitrary(g);
-            let duration = u32::arbitrary(g);
+            let offset = Timecode::arbitrary(g);
+            let duration = Timecode::arbitrary(g);
+            let copy = bool::arbitrary(g);
+            let segments = Vec::<SegmentSpec>::arbitrary(g);
+            let auto_scenes = bool::arbitrary(g);
+            let scene_threshold = f32::arbitrary(g);
+            let scene_min_length = f32::arbitrary(g);
+            let faststart = bool::arbitrary(g);
+            let fragmented = bool::arbitrary(g);
+            let fade_in = Option::<Timecode>::arbitrary(g);
+            let fade_out = Option::<Timecode>::arbitrary(g);
+            // Occasionally use an MP4 family extension so `movflags_chunks` is exercised.
+            let output = match bool::arbitrary(g) {
+                true => format!("{output}.mp4"),
+                false => output,
+            };
             Args {
                 input,
                 output,
                 duration,
                 offset,
+                copy,
+                segments,
+                auto_scenes,
+                scene_threshold,
+                scene_min_length,
+                faststart,
+                fragmented,
+                fade_in,
+                fade_out,
             }
         }
     }
+
+    impl Arbitrary for Timecode {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            // Keep generated values finite and non-negative, like a real offset/duration, with a
+            // millisecond-scale fractional part to exercise sub-second precision.
+            let whole = u32::arbitrary(g) as f64;
+            let millis = u16::arbitrary(g) % 1000;
+            Timecode::from_seconds(whole + millis as f64 / 1000.0)
+        }
+    }
+
+    impl Arbitrary for SegmentSpec {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            // `SegmentSpec::from_str` rejects `start >= end`, so keep generated specs consistent
+            // with that invariant by pushing `end` past `start` by at least a second.
+            let start = Timecode::arbitrary(g);
+            let end = Timecode::from_seconds(start.seconds + 1.0 + u16::arbitrary(g) as f64);
+            SegmentSpec { start, end }
+        }
+    }
 }
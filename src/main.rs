@@ -2,24 +2,79 @@ use std::process::Command;
 
 use anyhow::Result;
 use clap::Parser;
-use clipwhisper::{args::Args, ClipCommand};
+use clipwhisper::scenes::{scenes_from_cut_points, SceneDetector};
+use clipwhisper::{args::Args, format_output_template, ClipCommand};
 use env_logger::Env;
 use log::{debug, info};
 
 fn main() -> Result<()> {
     start_logger();
 
-    let mut command: ClipCommand = Args::parse().into();
+    let args = Args::parse();
+    let auto_scenes = args.auto_scenes;
+    let scene_threshold = args.scene_threshold;
+    let scene_min_length = args.scene_min_length;
+
+    let mut command: ClipCommand = args.into();
 
     let max_length = get_max_length(&command.input.value);
 
     command.target = command.target.bind_values(max_length);
+    for segment in command.segments.iter_mut() {
+        *segment = segment.bind_values(max_length);
+    }
+
+    match auto_scenes {
+        true => run_auto_scenes(&command, max_length, scene_threshold, scene_min_length),
+        false => {
+            let ffmpeg_args = command.render_arguments();
+            info!("Clipping video with args: {:#?}: ", &ffmpeg_args);
+            run_ffmpeg(&command.executable, &ffmpeg_args)
+        }
+    }
+}
 
-    let ffmpeg_args = command.render_arguments();
+/// Detects scene cuts in `command.input`, then renders and runs one ffmpeg invocation per scene,
+/// naming each output by interpolating `{index}`/`{start}` into `command.output.value`.
+fn run_auto_scenes(
+    command: &ClipCommand,
+    max_length: f32,
+    scene_threshold: f32,
+    scene_min_length: f32,
+) -> Result<()> {
+    let detector = SceneDetector::new(command.input.value.clone(), scene_threshold);
+    let detect_args = detector.render_arguments();
+
+    debug!("Detecting scenes with args: {:#?}", &detect_args);
+
+    let output = Command::new(&command.executable)
+        .args(detect_args)
+        .output()
+        .expect("Scene detection ffmpeg command failed");
+    let stderr = String::from_utf8(output.stderr).expect("Scene detection produced invalid utf8");
+
+    let cut_points = SceneDetector::parse_cut_points(&stderr);
+    let scenes = scenes_from_cut_points(&cut_points, max_length as f64, scene_min_length as f64);
+
+    for (index, scene) in scenes.into_iter().enumerate() {
+        let mut scene_command = command.clone();
+        // `render_arguments` prefers `segments` over `target` when both are set; clear it so
+        // each scene renders its own detected `start`/`end` instead of the same `--segment` list.
+        scene_command.segments.clear();
+        scene_command.target = scene;
+        scene_command.output.value =
+            format_output_template(&command.output.value, index, scene.start);
+
+        let ffmpeg_args = scene_command.render_arguments();
+        info!("Clipping scene {} with args: {:#?}", index, &ffmpeg_args);
+        run_ffmpeg(&scene_command.executable, &ffmpeg_args)?;
+    }
 
-    info!("Clipping video with args: {:#?}: ", &ffmpeg_args);
+    Ok(())
+}
 
-    let exit_status = Command::new(command.executable)
+fn run_ffmpeg(executable: &str, ffmpeg_args: &[String]) -> Result<()> {
+    let exit_status = Command::new(executable)
         .args(ffmpeg_args)
         .output()
         .expect("Ffmpeg command failed")
@@ -0,0 +1,106 @@
+use crate::TargetTimeStamp;
+
+/// Runs an ffmpeg scene-detection pass over an input and parses its `showinfo` output into
+/// cut-point timestamps, for `--auto-scenes`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct SceneDetector {
+    /// Input file to scan for scene changes.
+    pub input: String,
+    /// Minimum `scene` score change to be considered a cut point.
+    pub threshold: f32,
+}
+
+impl SceneDetector {
+    /// Builds a `SceneDetector` for `input`, flagging a cut wherever the scene score exceeds
+    /// `threshold`.
+    pub fn new(input: String, threshold: f32) -> Self {
+        Self { input, threshold }
+    }
+
+    /// Renders the ffmpeg arguments for a detection-only pass: `showinfo` prints `pts_time:`
+    /// markers to stderr for every frame `select` flags as a scene change, and `-f null -`
+    /// discards the actual output.
+    pub fn render_arguments(&self) -> Vec<String> {
+        vec![
+            "-i".to_string(),
+            self.input.clone(),
+            "-vf".to_string(),
+            format!("select='gt(scene,{})',showinfo", self.threshold),
+            "-f".to_string(),
+            "null".to_string(),
+            "-".to_string(),
+        ]
+    }
+
+    /// Parses `pts_time:` markers out of `showinfo`'s stderr output, returning a sorted list of
+    /// cut-point timestamps in seconds.
+    pub fn parse_cut_points(stderr: &str) -> Vec<f64> {
+        let mut cut_points: Vec<f64> = stderr
+            .lines()
+            .filter_map(|line| line.split_once("pts_time:"))
+            .filter_map(|(_, rest)| rest.split_whitespace().next())
+            .filter_map(|value| value.parse().ok())
+            .collect();
+        cut_points.sort_by(|a, b| a.partial_cmp(b).expect("pts_time should never be NaN"));
+        cut_points
+    }
+}
+
+/// Converts a sorted list of cut-point timestamps into scenes bounded by the implicit start
+/// (`0`) and the probed `duration`, dropping any scene shorter than `min_length` to avoid noise
+/// from spurious cuts.
+pub fn scenes_from_cut_points(cut_points: &[f64], duration: f64, min_length: f64) -> Vec<TargetTimeStamp> {
+    let mut boundaries = Vec::with_capacity(cut_points.len() + 2);
+    boundaries.push(0.0);
+    boundaries.extend(cut_points.iter().copied());
+    boundaries.push(duration);
+    boundaries.dedup();
+
+    boundaries
+        .windows(2)
+        .map(|window| TargetTimeStamp {
+            start: window[0],
+            end: window[1],
+        })
+        .filter(|scene| scene.end - scene.start >= min_length)
+        .filter(|scene| scene.start < scene.end)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_pts_time_from_showinfo() {
+        let stderr = "\
+[Parsed_showinfo_1 @ 0x0] n:0 pts:0 pts_time:0.5 pos:0
+[Parsed_showinfo_1 @ 0x0] n:1 pts:0 pts_time:12.25 pos:0
+";
+        assert_eq!(SceneDetector::parse_cut_points(stderr), vec![0.5, 12.25]);
+    }
+
+    #[test]
+    fn test_scenes_include_implicit_start_and_end() {
+        let scenes = scenes_from_cut_points(&[10.0], 20.0, 0.0);
+        assert_eq!(
+            scenes,
+            vec![
+                TargetTimeStamp { start: 0.0, end: 10.0 },
+                TargetTimeStamp { start: 10.0, end: 20.0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_short_scenes_are_dropped() {
+        let scenes = scenes_from_cut_points(&[10.0, 10.5], 20.0, 1.0);
+        assert_eq!(
+            scenes,
+            vec![
+                TargetTimeStamp { start: 0.0, end: 10.0 },
+                TargetTimeStamp { start: 10.5, end: 20.0 },
+            ]
+        );
+    }
+}
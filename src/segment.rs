@@ -0,0 +1,81 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::timecode::Timecode;
+
+/// A `start-end` timecode range given to `--segment`, e.g. `0:10-0:25`. Each endpoint accepts
+/// any format `Timecode` understands.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct SegmentSpec {
+    /// Start of the segment.
+    pub start: Timecode,
+    /// End of the segment.
+    pub end: Timecode,
+}
+
+impl FromStr for SegmentSpec {
+    type Err = SegmentParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SegmentParseError(s.to_string());
+
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let start: Timecode = start.parse().map_err(|_| invalid())?;
+        let end: Timecode = end.parse().map_err(|_| invalid())?;
+
+        if start.seconds >= end.seconds {
+            return Err(invalid());
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+/// Error returned when a `--segment` value isn't a valid `start-end` timecode range, including a
+/// range whose `start` doesn't fall before `end`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct SegmentParseError(String);
+
+impl fmt::Display for SegmentParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid segment `{}`, expected `start-end` with start < end, e.g. `0:10-0:25`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for SegmentParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_segment_range() {
+        let segment: SegmentSpec = "0:10-0:25".parse().unwrap();
+        assert_eq!(segment.start.seconds, 10.0);
+        assert_eq!(segment.end.seconds, 25.0);
+    }
+
+    #[test]
+    fn test_rejects_missing_separator() {
+        assert!("0:10".parse::<SegmentSpec>().is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_endpoint() {
+        assert!("a-0:25".parse::<SegmentSpec>().is_err());
+    }
+
+    #[test]
+    fn test_rejects_start_after_end() {
+        assert!("0:10-0:05".parse::<SegmentSpec>().is_err());
+    }
+
+    #[test]
+    fn test_rejects_start_equal_to_end() {
+        assert!("0:10-0:10".parse::<SegmentSpec>().is_err());
+    }
+}
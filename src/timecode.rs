@@ -0,0 +1,129 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A timestamp parsed from the command-line. Accepts plain seconds (`90`, `12.5`) as well as
+/// colon-separated timecodes (`HH:MM:SS.mmm` / `MM:SS.mmm`) the way a video editor or subtitle
+/// file would paste them, e.g. `1:23:45.500` or `0:05,250`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Timecode {
+    /// The timecode represented as seconds, with sub-second precision preserved.
+    pub seconds: f64,
+}
+
+impl Timecode {
+    /// Build a `Timecode` directly from a number of seconds.
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self { seconds }
+    }
+}
+
+impl fmt::Display for Timecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.seconds)
+    }
+}
+
+impl FromStr for Timecode {
+    type Err = TimecodeParseError;
+
+    /// Parses plain seconds, or up to three colon-separated `HH:MM:SS` fields. The final field
+    /// may carry a fractional part using either `.` or `,` as the decimal separator.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TimecodeParseError(s.to_string());
+
+        let mut fields = s.split(':').rev();
+
+        let seconds: f64 = fields
+            .next()
+            .ok_or_else(invalid)?
+            .replace(',', ".")
+            .parse()
+            .map_err(|_| invalid())?;
+
+        let minutes: f64 = fields
+            .next()
+            .map(|field| field.parse())
+            .transpose()
+            .map_err(|_| invalid())?
+            .unwrap_or(0.0);
+
+        let hours: f64 = fields
+            .next()
+            .map(|field| field.parse())
+            .transpose()
+            .map_err(|_| invalid())?
+            .unwrap_or(0.0);
+
+        if fields.next().is_some() {
+            return Err(invalid());
+        }
+
+        if hours < 0.0 || minutes < 0.0 || seconds < 0.0 {
+            return Err(invalid());
+        }
+
+        Ok(Self::from_seconds(hours * 3600.0 + minutes * 60.0 + seconds))
+    }
+}
+
+/// Error returned when a `--offset`/`--duration` value isn't a valid timecode, including a
+/// negative one.
+#[derive(PartialEq, Clone, Debug)]
+pub struct TimecodeParseError(String);
+
+impl fmt::Display for TimecodeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid timecode `{}`, expected a non-negative value in seconds or HH:MM:SS.mmm",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for TimecodeParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_plain_seconds() {
+        assert_eq!(Timecode::from_str("90").unwrap().seconds, 90.0);
+        assert_eq!(Timecode::from_str("12.5").unwrap().seconds, 12.5);
+    }
+
+    #[test]
+    fn test_parses_minutes_and_seconds() {
+        assert_eq!(Timecode::from_str("0:05,250").unwrap().seconds, 5.25);
+        assert_eq!(Timecode::from_str("1:30").unwrap().seconds, 90.0);
+    }
+
+    #[test]
+    fn test_parses_hours_minutes_seconds() {
+        let timecode = Timecode::from_str("1:23:45.500").unwrap();
+        assert_eq!(timecode.seconds, 1.0 * 3600.0 + 23.0 * 60.0 + 45.5);
+    }
+
+    #[test]
+    fn test_rejects_too_many_fields() {
+        assert!(Timecode::from_str("1:2:3:4").is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_numeric_fields() {
+        assert!(Timecode::from_str("a:bb").is_err());
+    }
+
+    #[test]
+    fn test_rejects_negative_seconds() {
+        assert!(Timecode::from_str("-5").is_err());
+    }
+
+    #[test]
+    fn test_rejects_negative_fields() {
+        assert!(Timecode::from_str("-1:30").is_err());
+        assert!(Timecode::from_str("1:-30").is_err());
+        assert!(Timecode::from_str("-1:23:45").is_err());
+    }
+}